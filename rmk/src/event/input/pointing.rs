@@ -4,11 +4,11 @@ use rmk_macro::input_event;
 use serde::{Deserialize, Serialize};
 
 #[input_event(channel_size = 8)]
-#[derive(Serialize, Deserialize, Clone, Debug, Copy, MaxSize)]
+#[derive(Serialize, Deserialize, Clone, Debug, Copy, MaxSize, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct PointingEvent(pub [AxisEvent; 3]);
 
-#[derive(Serialize, Deserialize, Clone, Debug, Copy, MaxSize)]
+#[derive(Serialize, Deserialize, Clone, Debug, Copy, MaxSize, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct AxisEvent {
     /// The axis event value type, relative or absolute
@@ -19,7 +19,7 @@ pub struct AxisEvent {
     pub value: i16,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, Copy, MaxSize)]
+#[derive(Serialize, Deserialize, Clone, Debug, Copy, MaxSize, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum AxisValType {
     /// The axis value is relative
@@ -28,7 +28,7 @@ pub enum AxisValType {
     Abs,
 }
 
-#[derive(Serialize, Deserialize, Clone, Copy, Debug, MaxSize)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, MaxSize, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[non_exhaustive]
 pub enum Axis {
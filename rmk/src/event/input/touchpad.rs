@@ -6,7 +6,7 @@ use crate::event::AxisEvent;
 
 /// Event for multi-touch touchpad
 #[input_event(channel_size = 8)]
-#[derive(Serialize, Deserialize, Clone, Copy, Debug, MaxSize)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, MaxSize, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct TouchpadEvent {
     /// Finger slot
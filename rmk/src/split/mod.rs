@@ -21,7 +21,7 @@ pub const SPLIT_MESSAGE_MAX_SIZE: usize = SplitMessage::POSTCARD_MAX_SIZE + 4;
 
 /// Message used from central & peripheral communication
 #[repr(u8)]
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, MaxSize)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, MaxSize, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub(crate) enum SplitMessage {
     /// Keyboard event, from peripheral to central
@@ -47,3 +47,146 @@ pub(crate) enum SplitMessage {
     #[cfg(feature = "_ble")]
     BatteryState(BatteryStateEvent),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{Axis, AxisEvent, AxisValType};
+    use crate::input_device::rotary_encoder::Direction;
+
+    fn axis(typ: AxisValType, axis: Axis, value: i16) -> AxisEvent {
+        AxisEvent { typ, axis, value }
+    }
+
+    /// One or more messages of every `SplitMessage` variant.
+    ///
+    /// Values at the ends of their ranges are used so that varint encoding produces the longest frames.
+    fn all_messages() -> Vec<SplitMessage> {
+        let messages = vec![
+            SplitMessage::Key(KeyboardEvent::key(0, 0, true)),
+            SplitMessage::Key(KeyboardEvent::key(3, 11, false)),
+            SplitMessage::Key(KeyboardEvent::key(u8::MAX, u8::MAX, true)),
+            SplitMessage::Key(KeyboardEvent::rotary_encoder(1, Direction::Clockwise, true)),
+            SplitMessage::Key(KeyboardEvent::rotary_encoder(
+                u8::MAX,
+                Direction::CounterClockwise,
+                false,
+            )),
+            SplitMessage::Touchpad(TouchpadEvent {
+                finger: u8::MAX,
+                axis: [
+                    axis(AxisValType::Abs, Axis::X, i16::MIN),
+                    axis(AxisValType::Abs, Axis::Y, i16::MAX),
+                ],
+            }),
+            SplitMessage::Pointing(PointingEvent([
+                axis(AxisValType::Rel, Axis::X, -1),
+                axis(AxisValType::Rel, Axis::Y, 1),
+                axis(AxisValType::Rel, Axis::Z, 0),
+            ])),
+            SplitMessage::Pointing(PointingEvent([
+                axis(AxisValType::Abs, Axis::H, i16::MIN),
+                axis(AxisValType::Abs, Axis::V, i16::MAX),
+                axis(AxisValType::Rel, Axis::X, i16::MIN),
+            ])),
+            SplitMessage::LedState(true),
+            SplitMessage::LedState(false),
+            SplitMessage::ConnectionState(true),
+            SplitMessage::ConnectionState(false),
+            SplitMessage::Address([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]),
+            SplitMessage::Address([u8::MAX; 6]),
+            SplitMessage::ClearPeer,
+            SplitMessage::KeyboardIndicator(0b0001_0101),
+            SplitMessage::KeyboardIndicator(u8::MAX),
+            SplitMessage::Layer(0),
+            SplitMessage::Layer(u8::MAX),
+        ];
+        #[cfg(feature = "_ble")]
+        let messages = {
+            let mut messages = messages;
+            messages.extend([
+                SplitMessage::BatteryState(BatteryStateEvent::NotAvailable),
+                SplitMessage::BatteryState(BatteryStateEvent::Normal(100)),
+                SplitMessage::BatteryState(BatteryStateEvent::Charging),
+                SplitMessage::BatteryState(BatteryStateEvent::Charged),
+            ]);
+            messages
+        };
+        messages
+    }
+
+    /// Number of `SplitMessage` variants, which is also the number of indices returned by `variant_index`.
+    const VARIANT_COUNT: usize = if cfg!(feature = "_ble") { 10 } else { 9 };
+
+    /// The match is exhaustive, so adding a variant does not compile until it is given an index here.
+    /// `test_all_variants_covered` then fails until `all_messages` contains the new variant.
+    fn variant_index(message: &SplitMessage) -> usize {
+        match message {
+            SplitMessage::Key(_) => 0,
+            SplitMessage::Touchpad(_) => 1,
+            SplitMessage::Pointing(_) => 2,
+            SplitMessage::LedState(_) => 3,
+            SplitMessage::ConnectionState(_) => 4,
+            SplitMessage::Address(_) => 5,
+            SplitMessage::ClearPeer => 6,
+            SplitMessage::KeyboardIndicator(_) => 7,
+            SplitMessage::Layer(_) => 8,
+            #[cfg(feature = "_ble")]
+            SplitMessage::BatteryState(_) => 9,
+        }
+    }
+
+    /// Encode `message` into a scratch buffer larger than `SPLIT_MESSAGE_MAX_SIZE`,
+    /// so that an oversized frame shows up as a length instead of an encoding error.
+    fn encode(message: &SplitMessage, buf: &mut [u8; 2 * SPLIT_MESSAGE_MAX_SIZE]) -> usize {
+        postcard::to_slice_cobs(message, buf)
+            .unwrap_or_else(|e| panic!("Failed to encode {:?}: {}", message, e))
+            .len()
+    }
+
+    #[test]
+    fn test_all_variants_covered() {
+        let mut covered = [false; VARIANT_COUNT];
+        for message in all_messages() {
+            let index = variant_index(&message);
+            match covered.get_mut(index) {
+                Some(c) => *c = true,
+                None => panic!("Variant index {} of {:?} is not below VARIANT_COUNT", index, message),
+            }
+        }
+        for (index, c) in covered.iter().enumerate() {
+            assert!(*c, "Variant {} is not covered by all_messages", index);
+        }
+    }
+
+    #[test]
+    fn test_split_message_round_trip() {
+        for message in all_messages() {
+            let mut buf = [0_u8; 2 * SPLIT_MESSAGE_MAX_SIZE];
+            let len = encode(&message, &mut buf);
+            assert!(
+                len <= SPLIT_MESSAGE_MAX_SIZE,
+                "{:?} is encoded to {} bytes, more than SPLIT_MESSAGE_MAX_SIZE",
+                message,
+                len
+            );
+
+            let (decoded, unused) = postcard::take_from_bytes_cobs::<SplitMessage>(&mut buf[..len])
+                .unwrap_or_else(|e| panic!("Failed to decode {:?}: {}", message, e));
+            assert_eq!(decoded, message);
+            assert!(unused.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_max_size_pointing_fits() {
+        // Every axis value takes the longest varint encoding
+        let message = SplitMessage::Pointing(PointingEvent([
+            axis(AxisValType::Abs, Axis::X, i16::MIN),
+            axis(AxisValType::Abs, Axis::Y, i16::MIN),
+            axis(AxisValType::Abs, Axis::Z, i16::MIN),
+        ]));
+        let mut buf = [0_u8; 2 * SPLIT_MESSAGE_MAX_SIZE];
+        assert!(encode(&message, &mut buf) <= SPLIT_MESSAGE_MAX_SIZE);
+    }
+}
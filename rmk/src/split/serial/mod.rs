@@ -103,8 +103,128 @@ impl<S: Read + Write> SplitWriter for SerialSplitDriver<S> {
                 .write(&bytes[bytes.len() - remaining_bytes..])
                 .await
                 .map_err(|_e| SplitDriverError::SerialError)?;
+            if sent_bytes == 0 {
+                // `Write::write` must not return `Ok(0)` for a non-empty buffer, it should block until a byte can be
+                // written. A serial breaking that contract would make this loop spin forever, so fail instead
+                error!("Serial write made no progress, {} bytes are not sent", remaining_bytes);
+                return Err(SplitDriverError::SerialError);
+            }
             remaining_bytes -= sent_bytes;
         }
         Ok(bytes.len())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use embassy_futures::block_on;
+    use embedded_io_async::ErrorType;
+
+    use super::*;
+    use crate::event::KeyboardEvent;
+
+    // Init logger for tests
+    #[ctor::ctor]
+    fn init_log() {
+        let _ = env_logger::builder()
+            .filter_level(log::LevelFilter::Debug)
+            .is_test(true)
+            .try_init();
+    }
+
+    /// A serial port backed by two byte queues.
+    ///
+    /// Written bytes go to `tx`, and `loopback` moves them to `rx` so that the same driver reads them back.
+    /// Each `read` returns at most `read_chunk` bytes, which simulates a fragmented serial stream.
+    /// When `write_stalled` is set, every `write` returns `Ok(0)`.
+    struct MockReadWrite {
+        rx: VecDeque<u8>,
+        tx: VecDeque<u8>,
+        read_chunk: usize,
+        write_stalled: bool,
+    }
+
+    impl MockReadWrite {
+        fn new(read_chunk: usize) -> Self {
+            Self {
+                rx: VecDeque::new(),
+                tx: VecDeque::new(),
+                read_chunk,
+                write_stalled: false,
+            }
+        }
+
+        fn loopback(&mut self) {
+            self.rx.extend(self.tx.drain(..));
+        }
+    }
+
+    impl ErrorType for MockReadWrite {
+        type Error = core::convert::Infallible;
+    }
+
+    impl Read for MockReadWrite {
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+            let n = buf.len().min(self.read_chunk).min(self.rx.len());
+            for (dst, src) in buf.iter_mut().zip(self.rx.drain(..n)) {
+                *dst = src;
+            }
+            Ok(n)
+        }
+    }
+
+    impl Write for MockReadWrite {
+        async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+            if self.write_stalled {
+                return Ok(0);
+            }
+            self.tx.extend(buf);
+            Ok(buf.len())
+        }
+    }
+
+    #[test]
+    fn test_serial_loopback() {
+        let mut driver = SerialSplitDriver::new(MockReadWrite::new(usize::MAX));
+        let event = KeyboardEvent::key(2, 3, true);
+
+        let written = block_on(driver.write(&SplitMessage::Key(event))).unwrap();
+        assert_eq!(driver.serial.tx.len(), written);
+
+        driver.serial.loopback();
+        assert_eq!(block_on(driver.read()).unwrap(), SplitMessage::Key(event));
+        assert_eq!(driver.n_bytes_part, 0);
+    }
+
+    #[test]
+    fn test_serial_loopback_fragmented() {
+        let mut driver = SerialSplitDriver::new(MockReadWrite::new(3));
+        let events = [
+            KeyboardEvent::key(0, 1, true),
+            KeyboardEvent::key(4, 5, false),
+            KeyboardEvent::key(7, 0, true),
+        ];
+
+        for event in events {
+            block_on(driver.write(&SplitMessage::Key(event))).unwrap();
+        }
+        driver.serial.loopback();
+
+        for event in events {
+            assert_eq!(block_on(driver.read()).unwrap(), SplitMessage::Key(event));
+        }
+        assert!(driver.serial.rx.is_empty());
+    }
+
+    #[test]
+    fn test_serial_write_no_progress() {
+        let mut driver = SerialSplitDriver::new(MockReadWrite::new(usize::MAX));
+        driver.serial.write_stalled = true;
+
+        let result = block_on(driver.write(&SplitMessage::Key(KeyboardEvent::key(0, 0, true))));
+        assert!(matches!(result, Err(SplitDriverError::SerialError)));
+        assert!(driver.serial.tx.is_empty());
+    }
+}